@@ -1,5 +1,5 @@
 use arrow::legacy::time_zone::Tz;
-use chrono::{Datelike, NaiveDateTime, NaiveTime};
+use chrono::{DateTime, Datelike, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
 use polars_core::chunked_array::temporal::time_to_time64ns;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -7,18 +7,285 @@ use polars_utils::format_pl_smallstr;
 
 use crate::prelude::*;
 
+const NS_PER_DAY: i64 = 86_400_000_000_000;
+
+/// Controls what happens when a calendar step (e.g. adding a month) lands on a
+/// day-of-month that doesn't exist in the target month, such as Jan 31 + 1mo.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Clamp the day to the last valid day of the resulting month (e.g. Feb 31 -> Feb 28/29).
+    #[default]
+    Constrain,
+    /// Return a `ComputeError` instead of silently clamping.
+    Reject,
+}
+
+/// Controls how a step that lands on an ambiguous (fall-back) or nonexistent
+/// (spring-forward gap) wall-clock instant in `tz` is resolved to a UTC instant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Disambiguation {
+    /// Gap: the instant just after the gap. Fold: the first occurrence.
+    Earliest,
+    /// Gap: the instant just before the gap. Fold: the second occurrence.
+    Latest,
+    /// Same as `Earliest`; the common "just move forward" convention.
+    #[default]
+    Compatible,
+    /// Return a `ComputeError` instead of picking a side.
+    Reject,
+}
+
+/// A DST gap is never wider than this in practice; bounds the linear search in
+/// [`resolve_gap`] so it can't loop indefinitely on a malformed time zone.
+const MAX_GAP_SEARCH_MINUTES: i64 = 4 * 60;
+
+fn naive_datetime_at_utc(ts: i64, tu: TimeUnit) -> NaiveDateTime {
+    let (secs, nsecs) = match tu {
+        TimeUnit::Nanoseconds => (ts.div_euclid(1_000_000_000), ts.rem_euclid(1_000_000_000) as u32),
+        TimeUnit::Microseconds => (ts.div_euclid(1_000_000), (ts.rem_euclid(1_000_000) * 1_000) as u32),
+        TimeUnit::Milliseconds => (ts.div_euclid(1_000), (ts.rem_euclid(1_000) * 1_000_000) as u32),
+    };
+    DateTime::from_timestamp(secs, nsecs).unwrap().naive_utc()
+}
+
+fn naive_datetime_to_ts(ndt: NaiveDateTime, tu: TimeUnit) -> PolarsResult<i64> {
+    Ok(match tu {
+        TimeUnit::Nanoseconds => try_timestamp_nanos(&ndt)?,
+        TimeUnit::Microseconds => ndt.and_utc().timestamp_micros(),
+        TimeUnit::Milliseconds => ndt.and_utc().timestamp_millis(),
+    })
+}
+
+fn datetime_to_ts(dt: DateTime<Tz>, tu: TimeUnit) -> PolarsResult<i64> {
+    Ok(match tu {
+        TimeUnit::Nanoseconds => try_timestamp_nanos(&dt.naive_utc())?,
+        TimeUnit::Microseconds => dt.timestamp_micros(),
+        TimeUnit::Milliseconds => dt.timestamp_millis(),
+    })
+}
+
+/// `NaiveDateTime::timestamp_nanos_opt` is `None` outside ~1677-2262; report that
+/// clearly instead of panicking on `.unwrap()`.
+fn try_timestamp_nanos(ndt: &NaiveDateTime) -> PolarsResult<i64> {
+    ndt.and_utc().timestamp_nanos_opt().ok_or_else(|| {
+        polars_err!(
+            ComputeError:
+            "datetime '{}' is out of range for nanosecond precision (try `time_unit: \"us\"` instead)",
+            ndt
+        )
+    })
+}
+
+fn naive_datetime_at(ts: i64, tu: TimeUnit, tz: Option<&Tz>) -> NaiveDateTime {
+    let naive_utc = naive_datetime_at_utc(ts, tu);
+    match tz {
+        Some(tz) => tz.from_utc_datetime(&naive_utc).naive_local(),
+        None => naive_utc,
+    }
+}
+
+/// Under `Overflow::Reject`, error if adding `months` whole months to `start` would
+/// clamp the day-of-month, i.e. `start`'s day doesn't exist in the resulting
+/// (year, month). Only the month-add itself is checked, so this stays correct for
+/// intervals that mix months with weeks/days/time (e.g. `1mo2d`), where the day can
+/// legitimately differ from the start day without any month overflow happening.
+fn check_month_overflow(start: i64, months: i64, tu: TimeUnit, tz: Option<&Tz>) -> PolarsResult<()> {
+    if months == 0 {
+        return Ok(());
+    }
+    let start_local = naive_datetime_at(start, tu, tz);
+    let total_months = start_local.year() as i64 * 12 + (start_local.month() as i64 - 1) + months;
+    let year = total_months.div_euclid(12) as i32;
+    let month = (total_months.rem_euclid(12) + 1) as u32;
+    polars_ensure!(
+        start_local.day() <= days_in_month(year, month),
+        ComputeError: "day {} does not exist in month {}-{:02} (`overflow` is set to `Reject`)",
+        start_local.day(), year, month
+    );
+    Ok(())
+}
+
+/// Walk forward (or backward) from a nonexistent local datetime in minute-sized steps
+/// until we land on one that `tz` can resolve, then binary-search back toward `local`
+/// to pin down the exact gap boundary to nanosecond precision, so `Earliest`/`Latest`
+/// land exactly on the gap edge rather than wherever the minute-granularity walk
+/// happened to stop.
+fn resolve_gap(local: NaiveDateTime, tu: TimeUnit, tz: &Tz, forward: bool) -> PolarsResult<i64> {
+    let step = chrono::Duration::minutes(1);
+    let mut anchor = local;
+    let mut found = false;
+    for _ in 0..MAX_GAP_SEARCH_MINUTES {
+        anchor = if forward { anchor + step } else { anchor - step };
+        if matches!(tz.from_local_datetime(&anchor), LocalResult::Single(_)) {
+            found = true;
+            break;
+        }
+    }
+    polars_ensure!(
+        found,
+        ComputeError: "could not resolve local datetime '{}' in time zone '{}': no valid instant found near the DST gap", local, tz
+    );
+
+    let (mut invalid, mut valid) = (local, anchor);
+    loop {
+        let gap_ns = (valid - invalid).num_nanoseconds().unwrap_or(0);
+        if gap_ns.abs() <= 1 {
+            break;
+        }
+        let mid = invalid + chrono::Duration::nanoseconds(gap_ns / 2);
+        if matches!(tz.from_local_datetime(&mid), LocalResult::Single(_)) {
+            valid = mid;
+        } else {
+            invalid = mid;
+        }
+    }
+
+    match tz.from_local_datetime(&valid) {
+        LocalResult::Single(dt) => datetime_to_ts(dt, tu),
+        _ => polars_bail!(
+            ComputeError: "could not resolve local datetime '{}' in time zone '{}': no valid instant found near the DST gap", local, tz
+        ),
+    }
+}
+
+/// Resolve a naive local datetime (the result of stepping in wall-clock terms) to a
+/// UTC instant, disambiguating DST gaps/folds according to `disambiguation`.
+fn resolve_local_datetime(
+    local: NaiveDateTime,
+    tu: TimeUnit,
+    tz: &Tz,
+    disambiguation: Disambiguation,
+) -> PolarsResult<i64> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) => datetime_to_ts(dt, tu),
+        LocalResult::None => match disambiguation {
+            Disambiguation::Earliest | Disambiguation::Compatible => {
+                resolve_gap(local, tu, tz, true)
+            }
+            Disambiguation::Latest => resolve_gap(local, tu, tz, false),
+            Disambiguation::Reject => {
+                polars_bail!(
+                    ComputeError: "datetime '{}' is non-existent in time zone '{}' (`disambiguation` is set to `Reject`)", local, tz
+                )
+            }
+        },
+        LocalResult::Ambiguous(earliest, latest) => match disambiguation {
+            Disambiguation::Earliest | Disambiguation::Compatible => datetime_to_ts(earliest, tu),
+            Disambiguation::Latest => datetime_to_ts(latest, tu),
+            Disambiguation::Reject => {
+                polars_bail!(
+                    ComputeError: "datetime '{}' is ambiguous in time zone '{}' (`disambiguation` is set to `Reject`)", local, tz
+                )
+            }
+        },
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    let first_of_next = NaiveDate::from_ymd_opt(next_year, next_month, 1).unwrap();
+    let first_of_this = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+    (first_of_next - first_of_this).num_days() as u32
+}
+
+/// Add whole months to a naive datetime, clamping the day to the last valid day of
+/// the resulting month (`Overflow::Constrain` semantics).
+fn add_months_clamped(ndt: NaiveDateTime, months: i64) -> NaiveDateTime {
+    let total_months = ndt.year() as i64 * 12 + (ndt.month() as i64 - 1) + months;
+    let year = total_months.div_euclid(12) as i32;
+    let month = (total_months.rem_euclid(12) + 1) as u32;
+    let day = ndt.day().min(days_in_month(year, month));
+    NaiveDate::from_ymd_opt(year, month, day)
+        .unwrap()
+        .and_time(ndt.time())
+}
+
+fn ns_to_tu(ns: i64, tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => ns,
+        TimeUnit::Microseconds => ns.div_euclid(1_000),
+        TimeUnit::Milliseconds => ns.div_euclid(1_000_000),
+    }
+}
+
+/// Balanced step: apply the calendar (week/month/day) portion of the interval using
+/// wall-clock/DST-aware addition, then add the sub-day part as an absolute elapsed
+/// offset, carrying any overflow of the sub-day part beyond 24h into extra whole days.
+fn apply_time_add_balanced(
+    start: i64,
+    interval: &Duration,
+    i: i64,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+    overflow: Overflow,
+    disambiguation: Disambiguation,
+) -> PolarsResult<i64> {
+    let sign: i64 = if interval.negative { -1 } else { 1 };
+
+    let total_time_ns = sign * interval.nanoseconds() * i;
+    let extra_days = total_time_ns.div_euclid(NS_PER_DAY);
+    let rem_ns = total_time_ns.rem_euclid(NS_PER_DAY);
+
+    let total_months = sign * interval.months() * i;
+    let total_days = sign * (interval.weeks() * 7 + interval.days()) * i + extra_days;
+
+    let start_local = naive_datetime_at(start, tu, tz);
+    let stepped_local =
+        add_months_clamped(start_local, total_months) + chrono::Duration::days(total_days);
+
+    let t_after_cal = match tz {
+        Some(tz) => resolve_local_datetime(stepped_local, tu, tz, disambiguation)?,
+        None => naive_datetime_to_ts(stepped_local, tu)?,
+    };
+
+    if overflow == Overflow::Reject {
+        check_month_overflow(start, total_months, tu, tz)?;
+    }
+
+    Ok(t_after_cal + ns_to_tu(rem_ns, tu))
+}
+
 fn apply_time_add(
     start: i64,
     interval: &Duration,
     i: i64,
     tu: TimeUnit,
-    tz: Option<&Tz>
+    tz: Option<&Tz>,
+    overflow: Overflow,
+    disambiguation: Disambiguation,
+    time_balancing: bool,
 ) -> PolarsResult<i64> {
-    match tu {
-        TimeUnit::Nanoseconds => Duration::add_ns(&(*interval * i), start, tz),
-        TimeUnit::Microseconds => Duration::add_us(&(*interval * i), start, tz),
-        TimeUnit::Milliseconds => Duration::add_ms(&(*interval * i), start, tz),
+    if time_balancing {
+        return apply_time_add_balanced(start, interval, i, tu, tz, overflow, disambiguation);
     }
+
+    let t = match tz {
+        Some(tz) => {
+            // Step in wall-clock/local terms first (DST-naive), then resolve the
+            // resulting naive local datetime back to a UTC instant ourselves.
+            let start_local = naive_datetime_at(start, tu, Some(tz));
+            let start_local_ts = naive_datetime_to_ts(start_local, tu)?;
+            let stepped_local_ts = match tu {
+                TimeUnit::Nanoseconds => Duration::add_ns(&(*interval * i), start_local_ts, None),
+                TimeUnit::Microseconds => Duration::add_us(&(*interval * i), start_local_ts, None),
+                TimeUnit::Milliseconds => Duration::add_ms(&(*interval * i), start_local_ts, None),
+            }?;
+            let stepped_local = naive_datetime_at_utc(stepped_local_ts, tu);
+            resolve_local_datetime(stepped_local, tu, tz, disambiguation)?
+        }
+        None => match tu {
+            TimeUnit::Nanoseconds => Duration::add_ns(&(*interval * i), start, None),
+            TimeUnit::Microseconds => Duration::add_us(&(*interval * i), start, None),
+            TimeUnit::Milliseconds => Duration::add_ms(&(*interval * i), start, None),
+        }?,
+    };
+
+    if overflow == Overflow::Reject {
+        let sign: i64 = if interval.negative { -1 } else { 1 };
+        check_month_overflow(start, sign * interval.months() * i, tu, tz)?;
+    }
+
+    Ok(t)
 }
 
 
@@ -36,11 +303,14 @@ pub fn date_range(
     closed: ClosedWindow,
     tu: TimeUnit,
     tz: Option<&Tz>,
+    overflow: Overflow,
+    disambiguation: Disambiguation,
+    time_balancing: bool,
 ) -> PolarsResult<DatetimeChunked> {
     let (start, end) = match tu {
         TimeUnit::Nanoseconds => (
-            start.and_utc().timestamp_nanos_opt().unwrap(),
-            end.and_utc().timestamp_nanos_opt().unwrap(),
+            try_timestamp_nanos(&start)?,
+            try_timestamp_nanos(&end)?,
         ),
         TimeUnit::Microseconds => (
             start.and_utc().timestamp_micros(),
@@ -51,7 +321,18 @@ pub fn date_range(
             end.and_utc().timestamp_millis(),
         ),
     };
-    datetime_range_impl(name, start, end, interval, closed, tu, tz)
+    datetime_range_impl(
+        name,
+        start,
+        end,
+        interval,
+        closed,
+        tu,
+        tz,
+        overflow,
+        disambiguation,
+        time_balancing,
+    )
 }
 
 #[doc(hidden)]
@@ -63,10 +344,23 @@ pub fn datetime_range_impl(
     closed: ClosedWindow,
     tu: TimeUnit,
     tz: Option<&Tz>,
+    overflow: Overflow,
+    disambiguation: Disambiguation,
+    time_balancing: bool,
 ) -> PolarsResult<DatetimeChunked> {
     let out = Int64Chunked::new_vec(
         name,
-        datetime_range_i64(start, end, interval, closed, tu, tz)?,
+        datetime_range_i64(
+            start,
+            end,
+            interval,
+            closed,
+            tu,
+            tz,
+            overflow,
+            disambiguation,
+            time_balancing,
+        )?,
     );
     let mut out = match tz {
         #[cfg(feature = "timezones")]
@@ -74,7 +368,11 @@ pub fn datetime_range_impl(
         _ => out.into_datetime(tu, None),
     };
 
-    out.set_sorted_flag(IsSorted::Ascending);
+    out.set_sorted_flag(if interval.negative {
+        IsSorted::Descending
+    } else {
+        IsSorted::Ascending
+    });
     Ok(out)
 }
 
@@ -101,14 +399,49 @@ pub fn time_range_impl(
 ) -> PolarsResult<TimeChunked> {
     let mut out = Int64Chunked::new_vec(
         name,
-        datetime_range_i64(start, end, interval, closed, TimeUnit::Nanoseconds, None)?,
+        datetime_range_i64(
+            start,
+            end,
+            interval,
+            closed,
+            TimeUnit::Nanoseconds,
+            None,
+            Overflow::Constrain,
+            Disambiguation::Compatible,
+            false,
+        )?,
     )
     .into_time();
 
-    out.set_sorted_flag(IsSorted::Ascending);
+    out.set_sorted_flag(if interval.negative {
+        IsSorted::Descending
+    } else {
+        IsSorted::Ascending
+    });
     Ok(out)
 }
 
+/// A conservative capacity hint for the output `Vec`: `(|end - start| / duration) + 1`,
+/// computed with checked arithmetic throughout. Degrades to `0` on overflow rather than
+/// panicking or allocating based on garbage, since this is only ever a hint.
+fn checked_range_len(start: i64, end: i64, duration: i64) -> usize {
+    if duration == 0 {
+        return 0;
+    }
+    let span = match start.checked_sub(end).and_then(i64::checked_abs) {
+        Some(span) => span,
+        None => return 0,
+    };
+    let duration = match duration.checked_abs() {
+        Some(duration) => duration,
+        None => return 0,
+    };
+    span.checked_div(duration)
+        .and_then(|n| n.checked_add(1))
+        .and_then(|n| usize::try_from(n).ok())
+        .unwrap_or(0)
+}
+
 /// vector of i64 representing temporal values
 pub(crate) fn datetime_range_i64(
     start: i64,
@@ -117,17 +450,25 @@ pub(crate) fn datetime_range_i64(
     closed: ClosedWindow,
     tu: TimeUnit,
     tz: Option<&Tz>,
+    overflow: Overflow,
+    disambiguation: Disambiguation,
+    time_balancing: bool,
 ) -> PolarsResult<Vec<i64>> {
-    if start > end {
+    if !interval.negative && start > end {
+        return Ok(Vec::new());
+    }
+    if interval.negative && start < end {
         return Ok(Vec::new());
     }
     polars_ensure!(
-        !interval.negative && !interval.is_zero(),
-        ComputeError: "`interval` must be positive"
+        !interval.is_zero(),
+        ComputeError: "`interval` must not be zero"
     );
 
-    // Fast path when interval has only nsec interval component
-    if interval.weeks()==0 && interval.months() == 0 && interval.days()==0 {
+    // Fast path when interval has only nsec interval component.
+    // Descending ranges fall through to the general (slower) loop below, as they're
+    // the less common case and don't fit neatly into a `step_by` range.
+    if !interval.negative && interval.weeks()==0 && interval.months() == 0 && interval.days()==0 {
         let interval_nsec = interval.nanoseconds();
         match closed {
             ClosedWindow::Both => {
@@ -145,14 +486,11 @@ pub(crate) fn datetime_range_i64(
         }
     }
     
-    let size: usize = match tu {
-        TimeUnit::Nanoseconds => ((end - start) / interval.duration_ns() + 1) as usize,
-        TimeUnit::Microseconds => ((end - start) / interval.duration_us() + 1) as usize,
-        TimeUnit::Milliseconds => ((end - start) / interval.duration_ms() + 1) as usize,
+    let size = match tu {
+        TimeUnit::Nanoseconds => checked_range_len(start, end, interval.duration_ns()),
+        TimeUnit::Microseconds => checked_range_len(start, end, interval.duration_us()),
+        TimeUnit::Milliseconds => checked_range_len(start, end, interval.duration_ms()),
     };
-    
-    let size: usize;
-    let offset_fn: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
 
     let mut ts = Vec::with_capacity(size);
 
@@ -161,26 +499,34 @@ pub(crate) fn datetime_range_i64(
         ClosedWindow::Right | ClosedWindow::None => 1,
     };
     
-    let mut t = apply_time_add(start, &interval, i, tu, tz)?;
+    let mut t = apply_time_add(start, &interval, i, tu, tz, overflow, disambiguation, time_balancing)?;
     i += 1;
 
+    // Direction-aware bounds check: an ascending (positive) interval walks up towards
+    // `end`, a descending (negative) interval walks down towards it.
+    let out_of_range = |t: i64| (!interval.negative && t > end) || (interval.negative && t < end);
+    let out_of_range_strict =
+        |t: i64| (!interval.negative && t >= end) || (interval.negative && t <= end);
+
     match closed {
         ClosedWindow::Both | ClosedWindow::Right => {
-            while t <= end {
+            while !out_of_range(t) {
                 ts.push(t);
-                t = apply_time_add(start, &interval, i, tu, tz)?;
+                t = apply_time_add(start, &interval, i, tu, tz, overflow, disambiguation, time_balancing)?;
                 i += 1;
             }
         }
         ClosedWindow::Left | ClosedWindow::None => {
-            while t < end {
+            while !out_of_range_strict(t) {
                 ts.push(t);
-                t = apply_time_add(start, &interval, i, tu, tz)?;
+                t = apply_time_add(start, &interval, i, tu, tz, overflow, disambiguation, time_balancing)?;
                 i += 1;
             }
         }
     }
-    
-    debug_assert!(size >= ts.len());
+
+    // `size` is only ever a best-effort capacity hint (see `checked_range_len`) and can
+    // legitimately degrade to 0 on overflow even though `ts` ends up non-empty, so no
+    // lower-bound invariant holds between the two.
     Ok(ts)
 }